@@ -1,4 +1,9 @@
-use std::{fs, path::Path, process, str};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process, str,
+    sync::Mutex,
+};
 
 use colour::e_red;
 use once_cell::sync::Lazy;
@@ -38,6 +43,13 @@ casper-types = {{ git = "{0}", branch = "{1}" }}
     None => String::new(),
 });
 
+/// The paths of generated files which are missing or differ from the existing files on disk when
+/// running with `--check`.
+static OUTDATED_FILES: Lazy<Mutex<Vec<PathBuf>>> = Lazy::new(|| Mutex::new(Vec::new()));
+/// The paths of starter files which are missing or differ from the existing files on disk when
+/// running with `--check`.  These are expected to diverge, so are only reported for information.
+static DIVERGED_STARTER_FILES: Lazy<Mutex<Vec<PathBuf>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
 pub fn print_error_and_exit(msg: &str) -> ! {
     e_red!("error");
     eprintln!("{}", msg);
@@ -45,6 +57,10 @@ pub fn print_error_and_exit(msg: &str) -> ! {
 }
 
 pub fn create_dir_all<P: AsRef<Path>>(path: P) {
+    if ARGS.check() {
+        return;
+    }
+
     if let Err(error) = fs::create_dir_all(path.as_ref()) {
         print_error_and_exit(&format!(
             ": failed to create '{}': {}",
@@ -55,6 +71,34 @@ pub fn create_dir_all<P: AsRef<Path>>(path: P) {
 }
 
 pub fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) {
+    write_or_check(path, contents, &OUTDATED_FILES)
+}
+
+/// Writes a file which is only a starting point for the user, e.g. the contract source, so is
+/// expected to diverge from its template after generation.  When running with `--check`,
+/// differences are recorded separately and don't cause the check to fail.
+pub fn write_starter_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) {
+    write_or_check(path, contents, &DIVERGED_STARTER_FILES)
+}
+
+fn write_or_check<P: AsRef<Path>, C: AsRef<[u8]>>(
+    path: P,
+    contents: C,
+    mismatched_files: &Mutex<Vec<PathBuf>>,
+) {
+    if ARGS.check() {
+        let is_current = fs::read(path.as_ref())
+            .map(|existing_contents| existing_contents == contents.as_ref())
+            .unwrap_or(false);
+        if !is_current {
+            mismatched_files
+                .lock()
+                .expect("should lock mismatched files")
+                .push(path.as_ref().to_path_buf());
+        }
+        return;
+    }
+
     if let Err(error) = fs::write(path.as_ref(), contents) {
         print_error_and_exit(&format!(
             ": failed to write to '{}': {}",
@@ -64,6 +108,24 @@ pub fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) {
     }
 }
 
+/// Returns the paths of all generated files found to be missing or out of date when running with
+/// `--check`.
+pub fn outdated_files() -> Vec<PathBuf> {
+    OUTDATED_FILES
+        .lock()
+        .expect("should lock outdated files")
+        .clone()
+}
+
+/// Returns the paths of all starter files found to be missing or to differ from their templates
+/// when running with `--check`.
+pub fn diverged_starter_files() -> Vec<PathBuf> {
+    DIVERGED_STARTER_FILES
+        .lock()
+        .expect("should lock diverged starter files")
+        .clone()
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::ops::Deref;
@@ -0,0 +1,60 @@
+//! Consts and functions used to generate a note recording the versions of the Casper crates and
+//! Rust toolchain which the project was generated against.
+
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    common::{self, CL_CONTRACT, CL_ENGINE_TEST_SUPPORT, CL_EXECUTION_ENGINE, CL_TYPES},
+    CasperOverrides, ARGS,
+};
+
+pub static COMPATIBILITY_MD: Lazy<PathBuf> =
+    Lazy::new(|| ARGS.root_path().join("COMPATIBILITY.md"));
+
+static CONTENTS: Lazy<String> = Lazy::new(|| {
+    let mut dependencies = vec![&*CL_CONTRACT, &*CL_TYPES];
+    if !ARGS.no_tests() {
+        dependencies.push(&*CL_ENGINE_TEST_SUPPORT);
+        dependencies.push(&*CL_EXECUTION_ENGINE);
+    }
+    let dependency_lines: String = dependencies
+        .into_iter()
+        .map(|dependency| format!("* `{}` {}\n", dependency.name(), dependency.version()))
+        .collect();
+
+    let overrides_note = match ARGS.casper_overrides() {
+        Some(CasperOverrides::WorkspacePath(path)) => format!(
+            "\nThe Casper crates were patched to use the local casper-node workspace at `{}`, so \
+            the versions above may not apply.\n",
+            path.display()
+        ),
+        Some(CasperOverrides::GitRepo { url, branch }) => format!(
+            "\nThe Casper crates were patched to use branch `{}` of `{}`, so the versions above \
+            may not apply.\n",
+            branch, url
+        ),
+        None => String::new(),
+    };
+
+    format!(
+        r#"# Compatibility
+
+This project was generated by `cargo-casper` {} against the following Casper crates:
+
+{}{}
+The contract is built using the Rust toolchain `{}`.
+
+If the project fails to build after updating any of these, compare against the versions above.
+"#,
+        env!("CARGO_PKG_VERSION"),
+        dependency_lines,
+        overrides_note,
+        ARGS.toolchain()
+    )
+});
+
+pub fn create() {
+    common::write_starter_file(&*COMPATIBILITY_MD, &*CONTENTS);
+}
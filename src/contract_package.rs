@@ -7,29 +7,58 @@ use once_cell::sync::Lazy;
 
 use crate::{
     common::{self, CL_CONTRACT, CL_TYPES, PATCH_SECTION},
-    ARGS,
+    Template, ARGS,
 };
 
-const PACKAGE_NAME: &str = "contract";
-static CONTRACT_PACKAGE_ROOT: Lazy<PathBuf> =
-    Lazy::new(|| ARGS.root_path().join(PACKAGE_NAME.replace('-', "_")));
+/// The name of the contract package's directory and binary target.
+pub static BIN_NAME: Lazy<String> = Lazy::new(|| ARGS.package_name().replace('-', "_"));
+/// The name of the Wasm file produced by building the contract package.
+pub static WASM_FILENAME: Lazy<String> = Lazy::new(|| format!("{}.wasm", &*BIN_NAME));
+
+static CONTRACT_PACKAGE_ROOT: Lazy<PathBuf> = Lazy::new(|| ARGS.root_path().join(&*BIN_NAME));
 static CARGO_TOML: Lazy<PathBuf> = Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("Cargo.toml"));
 static MAIN_RS: Lazy<PathBuf> = Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("src/main.rs"));
 static CONFIG_TOML: Lazy<PathBuf> = Lazy::new(|| CONTRACT_PACKAGE_ROOT.join(".cargo/config.toml"));
-static RUST_TOOLCHAIN: Lazy<PathBuf> = Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("rust-toolchain"));
+/// The toolchain file written by earlier versions of the tool, superseded by "rust-toolchain.toml".
+pub static LEGACY_RUST_TOOLCHAIN: Lazy<PathBuf> =
+    Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("rust-toolchain"));
+static RUST_TOOLCHAIN_TOML: Lazy<PathBuf> =
+    Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("rust-toolchain.toml"));
+
+/// The target the contract is built for, used in ".cargo/config.toml", "rust-toolchain.toml", the
+/// Makefile and the GitHub workflow so none of these can disagree.
+pub const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// The `casper-contract` feature providing the panic handler and global allocator needed by a
+/// `no_std` contract.  This is a default feature, so must be explicitly enabled when running with
+/// `--no-default-features`.
+const NO_STD_HELPERS_FEATURE: &str = "no-std-helpers";
 
 static CONTRACT_DEPENDENCIES: Lazy<String> = Lazy::new(|| {
+    if ARGS.no_default_features() {
+        format!(
+            "{}{}",
+            CL_CONTRACT.display_with_features(false, vec![NO_STD_HELPERS_FEATURE]),
+            CL_TYPES.display_with_features(false, vec![]),
+        )
+    } else {
+        format!(
+            "{}{}",
+            CL_CONTRACT.display_with_features(true, vec![]),
+            CL_TYPES.display_with_features(true, vec![]),
+        )
+    }
+});
+
+static CONFIG_TOML_CONTENTS: Lazy<String> = Lazy::new(|| {
     format!(
-        "{}{}",
-        CL_CONTRACT.display_with_features(true, vec![]),
-        CL_TYPES.display_with_features(true, vec![]),
+        r#"[build]
+target = "{}"
+"#,
+        WASM_TARGET
     )
 });
 
-const CONFIG_TOML_CONTENTS: &str = r#"[build]
-target = "wasm32-unknown-unknown"
-"#;
-
 static CARGO_TOML_CONTENTS: Lazy<String> = Lazy::new(|| {
     format!(
         r#"[package]
@@ -51,34 +80,54 @@ codegen-units = 1
 lto = true
 
 {}"#,
-        PACKAGE_NAME,
+        ARGS.package_name(),
         &*CONTRACT_DEPENDENCIES,
-        PACKAGE_NAME.replace('-', "_"),
+        &*BIN_NAME,
         &*PATCH_SECTION
     )
 });
 
 const MAIN_RS_CONTENTS: &str = include_str!("../resources/main.rs.in");
+const CEP18_MAIN_RS_CONTENTS: &str = include_str!("../resources/cep18_main.rs.in");
+const CEP78_MAIN_RS_CONTENTS: &str = include_str!("../resources/cep78_main.rs.in");
+
+/// The default toolchain channel, matching that used to build the casper-node contracts.
+pub const RUST_TOOLCHAIN_CONTENTS: &str = include_str!("../resources/rust-toolchain.in");
 
-const RUST_TOOLCHAIN_CONTENTS: &str = include_str!("../resources/rust-toolchain.in");
+static RUST_TOOLCHAIN_TOML_CONTENTS: Lazy<String> = Lazy::new(|| {
+    format!(
+        r#"[toolchain]
+channel = "{}"
+components = ["clippy", "rustfmt"]
+targets = ["{}"]
+"#,
+        ARGS.toolchain(),
+        WASM_TARGET
+    )
+});
 
 pub fn create() {
-    // Create "<PACKAGE_NAME>/src" folder and write "main.rs" inside.
+    // Create "<BIN_NAME>/src" folder and write "main.rs" inside.
     let src_folder = MAIN_RS.parent().expect("should have parent");
     common::create_dir_all(src_folder);
 
-    common::write_file(&*MAIN_RS, MAIN_RS_CONTENTS);
+    let main_rs_contents = match ARGS.template() {
+        Template::Default => MAIN_RS_CONTENTS,
+        Template::Cep18 => CEP18_MAIN_RS_CONTENTS,
+        Template::Cep78 => CEP78_MAIN_RS_CONTENTS,
+    };
+    common::write_starter_file(&*MAIN_RS, main_rs_contents);
 
-    // Create "<PACKAGE_NAME>/.cargo" folder and write "config.toml" inside.
+    // Create "<BIN_NAME>/.cargo" folder and write "config.toml" inside.
     let config_folder = CONFIG_TOML.parent().expect("should have parent");
     common::create_dir_all(config_folder);
-    common::write_file(&*CONFIG_TOML, CONFIG_TOML_CONTENTS);
+    common::write_file(&*CONFIG_TOML, &*CONFIG_TOML_CONTENTS);
 
-    // Write "<PACKAGE_NAME>/Cargo.toml".
+    // Write "<BIN_NAME>/Cargo.toml".
     common::write_file(&*CARGO_TOML, &*CARGO_TOML_CONTENTS);
 
-    // Write "<PACKAGE_NAME>/rust-toolchain".
-    common::write_file(&*RUST_TOOLCHAIN, RUST_TOOLCHAIN_CONTENTS);
+    // Write "<BIN_NAME>/rust-toolchain.toml".
+    common::write_file(&*RUST_TOOLCHAIN_TOML, &*RUST_TOOLCHAIN_TOML_CONTENTS);
 }
 
 #[cfg(test)]
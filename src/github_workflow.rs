@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+
+use crate::{common, contract_package::WASM_TARGET, makefile, ARGS};
+
+pub static WORKFLOW_YML: Lazy<PathBuf> =
+    Lazy::new(|| ARGS.root_path().join(".github/workflows/ci.yml"));
+
+static CONTENTS: Lazy<String> = Lazy::new(|| {
+    let apt_packages = makefile::required_apt_packages();
+    let install_tools_step = if apt_packages.is_empty() {
+        String::new()
+    } else {
+        format!(
+            r#"
+      - name: Install required tools
+        run: sudo apt-get update && sudo apt-get install -y {}
+"#,
+            apt_packages.join(" ")
+        )
+    };
+    format!(
+        r#"name: ci
+
+on:
+  push:
+  pull_request:
+
+jobs:
+  build-and-test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+
+      # The contract package's toolchain is pinned via its "rust-toolchain.toml" file.
+      - name: Install pinned toolchain
+        run: |
+          rustup toolchain install {} --profile minimal \
+            --component clippy,rustfmt --target {}
+{}
+      - run: make prepare
+      - run: make check-lint
+      - run: make {}
+"#,
+        ARGS.toolchain(),
+        WASM_TARGET,
+        install_tools_step,
+        makefile::ci_target()
+    )
+});
+
+pub fn create() {
+    // Create ".github/workflows" folder and write "ci.yml" inside.
+    let workflows_folder = WORKFLOW_YML.parent().expect("should have parent");
+    common::create_dir_all(workflows_folder);
+    common::write_file(&*WORKFLOW_YML, &*CONTENTS);
+}
@@ -0,0 +1,53 @@
+//! Functions used to prompt for options omitted from the command line when running with
+//! `--interactive`.
+
+use std::{
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+use crate::common;
+
+const ROOT_PATH_PROMPT: &str = "Path to new folder for contract and tests";
+
+/// Prints `question` and returns the trimmed line entered in response.
+///
+/// Exits with an error if stdin is closed, so a non-interactive caller can't hang waiting for
+/// input.
+fn prompt(question: &str) -> String {
+    print!("{}: ", question);
+    let _ = io::stdout().flush();
+
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        Ok(0) => common::print_error_and_exit(": no input received"),
+        Ok(_) => line.trim().to_string(),
+        Err(error) => common::print_error_and_exit(&format!(": failed to read input: {}", error)),
+    }
+}
+
+/// Prompts for the root path until a non-empty one is entered.
+pub fn prompt_for_root_path() -> PathBuf {
+    loop {
+        let answer = prompt(ROOT_PATH_PROMPT);
+        if !answer.is_empty() {
+            return PathBuf::from(answer);
+        }
+    }
+}
+
+/// Prompts for one of `choices` until a valid one is entered, returning `default` if the answer is
+/// empty.
+pub fn prompt_for_choice<'a>(question: &str, choices: &[&'a str], default: &'a str) -> &'a str {
+    let question = format!("{} ({}) [{}]", question, choices.join(", "), default);
+    loop {
+        let answer = prompt(&question);
+        if answer.is_empty() {
+            return default;
+        }
+        match choices.iter().copied().find(|choice| *choice == answer) {
+            Some(choice) => return choice,
+            None => println!("'{}' is not one of the available choices", answer),
+        }
+    }
+}
@@ -3,18 +3,27 @@
 #![deny(warnings)]
 
 pub mod common;
+mod compatibility_md;
 mod contract_package;
 pub mod dependency;
+mod github_workflow;
+mod interactive;
 mod makefile;
 mod tests_package;
 mod travis_yml;
+mod workspace_manifest;
 
 use std::{
     env,
     path::{Path, PathBuf},
 };
 
-use clap::{builder::ValueParser, crate_description, crate_name, crate_version, Arg, Command};
+use clap::{
+    builder::{PossibleValuesParser, ValueParser},
+    crate_description, crate_name, crate_version,
+    parser::ValueSource,
+    Arg, ArgAction, Command,
+};
 use once_cell::sync::Lazy;
 
 const USAGE: &str = r#"cargo casper [FLAGS] <path>
@@ -35,6 +44,95 @@ const GIT_URL_LONG: &str = "git-url";
 const GIT_BRANCH_ARG_NAME: &str = "git-branch";
 const GIT_BRANCH_LONG: &str = "git-branch";
 
+const CHECK_ARG_NAME: &str = "check";
+const CHECK_ARG_LONG: &str = "check";
+const CHECK_ARG_HELP: &str =
+    "Compare the files in an existing project at <path> against the current templates, reporting \
+    any which are out of date without modifying anything.  Pass the same options as when the \
+    project was generated, otherwise its files will be reported as missing or out of date.  The \
+    contract and tests sources and COMPATIBILITY.md are reported for information only";
+
+const NO_DEFAULT_FEATURES_ARG_NAME: &str = "no-default-features";
+const NO_DEFAULT_FEATURES_ARG_LONG: &str = "no-default-features";
+const NO_DEFAULT_FEATURES_ARG_HELP: &str =
+    "Disable default features of the Casper dependencies in the generated contract's manifest, \
+    retaining only those features required to build the contract";
+
+const NO_TESTS_ARG_NAME: &str = "no-tests";
+const NO_TESTS_ARG_LONG: &str = "no-tests";
+const NO_TESTS_ARG_HELP: &str =
+    "Generate only the contract package, omitting the tests package and its Makefile targets";
+
+const TESTS_BIN_NAME_ARG_NAME: &str = "tests-bin-name";
+const TESTS_BIN_NAME_ARG_LONG: &str = "tests-bin-name";
+const TESTS_BIN_NAME_ARG_VALUE_NAME: &str = "NAME";
+const TESTS_BIN_NAME_ARG_DEFAULT: &str = "integration-tests";
+const TESTS_BIN_NAME_ARG_HELP: &str =
+    "Name of the binary target in the generated tests package.  The source file is named to match";
+
+const NO_COMPAT_NOTE_ARG_NAME: &str = "no-compat-note";
+const NO_COMPAT_NOTE_ARG_LONG: &str = "no-compat-note";
+const NO_COMPAT_NOTE_ARG_HELP: &str =
+    "Don't generate COMPATIBILITY.md, which records the Casper crate versions and Rust toolchain \
+    the project was generated against";
+
+const REQUIRED_TOOLS_ARG_NAME: &str = "required-tools";
+const REQUIRED_TOOLS_ARG_LONG: &str = "required-tools";
+const REQUIRED_TOOLS_ARG_VALUE_NAME: &str = "TOOLS";
+const REQUIRED_TOOLS_ARG_DEFAULT: [&str; 3] = ["rustup", "cargo", "wasm-strip"];
+const REQUIRED_TOOLS_ARG_HELP: &str =
+    "Comma-separated list of tools which the generated Makefile's 'check-tools' recipe verifies \
+    are installed.  'make prepare' runs this check first";
+
+const CI_ARG_NAME: &str = "ci";
+const CI_ARG_LONG: &str = "ci";
+const CI_ARG_VALUE_NAME: &str = "CI";
+const CI_ARG_TRAVIS: &str = "travis";
+const CI_ARG_GITHUB: &str = "github";
+const CI_ARG_NONE: &str = "none";
+const CI_ARG_HELP: &str =
+    "The CI configuration to generate: a GitHub Actions workflow, a Travis CI config, or none";
+
+const TEMPLATE_ARG_NAME: &str = "template";
+const TEMPLATE_ARG_LONG: &str = "template";
+const TEMPLATE_ARG_VALUE_NAME: &str = "TEMPLATE";
+const TEMPLATE_ARG_DEFAULT: &str = "default";
+const TEMPLATE_ARG_CEP18: &str = "cep18";
+const TEMPLATE_ARG_CEP78: &str = "cep78";
+const TEMPLATE_ARG_VALUES: [&str; 3] =
+    [TEMPLATE_ARG_DEFAULT, TEMPLATE_ARG_CEP18, TEMPLATE_ARG_CEP78];
+const TEMPLATE_PROMPT: &str = "Template";
+const TEMPLATE_ARG_HELP: &str =
+    "The contract and tests to generate: a minimal example storing a named key, a CEP-18 fungible \
+    token, or a CEP-78 non-fungible token";
+
+const TOOLCHAIN_ARG_NAME: &str = "toolchain";
+const TOOLCHAIN_ARG_LONG: &str = "toolchain";
+const TOOLCHAIN_ARG_VALUE_NAME: &str = "CHANNEL";
+const TOOLCHAIN_ARG_HELP: &str =
+    "The Rust toolchain channel to pin the contract package to, e.g. 'stable' or \
+    'nightly-2023-03-25'.  Defaults to the toolchain used to build the casper-node contracts";
+
+const NAME_ARG_NAME: &str = "name";
+const NAME_ARG_LONG: &str = "name";
+const NAME_ARG_VALUE_NAME: &str = "NAME";
+const NAME_ARG_DEFAULT: &str = "contract";
+const NAME_ARG_HELP: &str =
+    "Name of the generated contract package, used for its crate name, binary target and \
+    directory.  The compiled Wasm file is named to match";
+
+const WORKSPACE_ARG_NAME: &str = "workspace";
+const WORKSPACE_ARG_LONG: &str = "workspace";
+const WORKSPACE_ARG_HELP: &str =
+    "Generate a top-level Cargo.toml making the tests package a member of a workspace, so Cargo \
+    commands can be run at the root.  The contract is excluded from the workspace and keeps its \
+    own Cargo.lock, as it's built for Wasm with its pinned toolchain via 'make build-contract'";
+
+const INTERACTIVE_ARG_NAME: &str = "interactive";
+const INTERACTIVE_ARG_LONG: &str = "interactive";
+const INTERACTIVE_ARG_HELP: &str =
+    "Prompt for the path and template if they're not given on the command line";
+
 const FAILURE_EXIT_CODE: i32 = 101;
 
 static ARGS: Lazy<Args> = Lazy::new(Args::new);
@@ -49,10 +147,41 @@ enum CasperOverrides {
     GitRepo { url: String, branch: String },
 }
 
+/// The CI service for which to generate a configuration file.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum CiProvider {
+    /// Generates ".github/workflows/ci.yml".
+    GitHub,
+    /// Generates ".travis.yml".
+    Travis,
+}
+
+/// The starter contract and matching tests to generate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Template {
+    /// A minimal contract which stores a runtime arg under a named key.
+    Default,
+    /// A minimal CEP-18 fungible token contract.
+    Cep18,
+    /// A minimal CEP-78 non-fungible token contract.
+    Cep78,
+}
+
 #[derive(Debug)]
 struct Args {
     root_path: PathBuf,
     casper_overrides: Option<CasperOverrides>,
+    check: bool,
+    no_default_features: bool,
+    no_tests: bool,
+    tests_bin_name: String,
+    no_compat_note: bool,
+    required_tools: Vec<String>,
+    ci_provider: Option<CiProvider>,
+    template: Template,
+    toolchain: String,
+    package_name: String,
+    workspace: bool,
 }
 
 impl Args {
@@ -76,7 +205,7 @@ impl Args {
 
         let root_path_arg = Arg::new(ROOT_PATH_ARG_NAME)
             .value_parser(ValueParser::path_buf())
-            .required(true)
+            .required_unless_present(INTERACTIVE_ARG_NAME)
             .value_name(ROOT_PATH_ARG_VALUE_NAME)
             .help(ROOT_PATH_ARG_HELP);
 
@@ -96,6 +225,83 @@ impl Args {
             .conflicts_with(WORKSPACE_PATH_ARG_NAME)
             .requires(GIT_URL_ARG_NAME);
 
+        let check_arg = Arg::new(CHECK_ARG_NAME)
+            .long(CHECK_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(CHECK_ARG_HELP);
+
+        let no_default_features_arg = Arg::new(NO_DEFAULT_FEATURES_ARG_NAME)
+            .long(NO_DEFAULT_FEATURES_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(NO_DEFAULT_FEATURES_ARG_HELP);
+
+        let no_tests_arg = Arg::new(NO_TESTS_ARG_NAME)
+            .long(NO_TESTS_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(NO_TESTS_ARG_HELP);
+
+        let tests_bin_name_arg = Arg::new(TESTS_BIN_NAME_ARG_NAME)
+            .long(TESTS_BIN_NAME_ARG_LONG)
+            .value_parser(parse_target_name)
+            .default_value(TESTS_BIN_NAME_ARG_DEFAULT)
+            .value_name(TESTS_BIN_NAME_ARG_VALUE_NAME)
+            .conflicts_with(NO_TESTS_ARG_NAME)
+            .help(TESTS_BIN_NAME_ARG_HELP);
+
+        let no_compat_note_arg = Arg::new(NO_COMPAT_NOTE_ARG_NAME)
+            .long(NO_COMPAT_NOTE_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(NO_COMPAT_NOTE_ARG_HELP);
+
+        let required_tools_arg = Arg::new(REQUIRED_TOOLS_ARG_NAME)
+            .long(REQUIRED_TOOLS_ARG_LONG)
+            .value_parser(PossibleValuesParser::new(
+                makefile::TOOLS.iter().map(|tool| tool.name),
+            ))
+            .value_delimiter(',')
+            .action(ArgAction::Append)
+            .default_values(REQUIRED_TOOLS_ARG_DEFAULT)
+            .value_name(REQUIRED_TOOLS_ARG_VALUE_NAME)
+            .help(REQUIRED_TOOLS_ARG_HELP);
+
+        let ci_arg = Arg::new(CI_ARG_NAME)
+            .long(CI_ARG_LONG)
+            .value_parser([CI_ARG_GITHUB, CI_ARG_TRAVIS, CI_ARG_NONE])
+            .default_value(CI_ARG_GITHUB)
+            .value_name(CI_ARG_VALUE_NAME)
+            .help(CI_ARG_HELP);
+
+        let template_arg = Arg::new(TEMPLATE_ARG_NAME)
+            .long(TEMPLATE_ARG_LONG)
+            .value_parser(TEMPLATE_ARG_VALUES)
+            .default_value(TEMPLATE_ARG_DEFAULT)
+            .value_name(TEMPLATE_ARG_VALUE_NAME)
+            .help(TEMPLATE_ARG_HELP);
+
+        let toolchain_arg = Arg::new(TOOLCHAIN_ARG_NAME)
+            .long(TOOLCHAIN_ARG_LONG)
+            .value_parser(parse_toolchain_channel)
+            .value_name(TOOLCHAIN_ARG_VALUE_NAME)
+            .help(TOOLCHAIN_ARG_HELP);
+
+        let name_arg = Arg::new(NAME_ARG_NAME)
+            .long(NAME_ARG_LONG)
+            .value_parser(parse_package_name)
+            .default_value(NAME_ARG_DEFAULT)
+            .value_name(NAME_ARG_VALUE_NAME)
+            .help(NAME_ARG_HELP);
+
+        let workspace_arg = Arg::new(WORKSPACE_ARG_NAME)
+            .long(WORKSPACE_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .conflicts_with(NO_TESTS_ARG_NAME)
+            .help(WORKSPACE_ARG_HELP);
+
+        let interactive_arg = Arg::new(INTERACTIVE_ARG_NAME)
+            .long(INTERACTIVE_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(INTERACTIVE_ARG_HELP);
+
         let arg_matches = Command::new(crate_name!())
             .version(crate_version!())
             .about(crate_description!())
@@ -104,12 +310,26 @@ impl Args {
             .arg(workspace_path_arg)
             .arg(git_url_arg)
             .arg(git_branch_arg)
+            .arg(check_arg)
+            .arg(no_default_features_arg)
+            .arg(no_tests_arg)
+            .arg(tests_bin_name_arg)
+            .arg(no_compat_note_arg)
+            .arg(required_tools_arg)
+            .arg(ci_arg)
+            .arg(template_arg)
+            .arg(toolchain_arg)
+            .arg(name_arg)
+            .arg(workspace_arg)
+            .arg(interactive_arg)
             .get_matches_from(filtered_args_iter);
 
-        let root_path = arg_matches
-            .get_one::<PathBuf>(ROOT_PATH_ARG_NAME)
-            .expect("expected path")
-            .clone();
+        let interactive = arg_matches.get_flag(INTERACTIVE_ARG_NAME);
+        let root_path = match arg_matches.get_one::<PathBuf>(ROOT_PATH_ARG_NAME) {
+            Some(root_path) => root_path.clone(),
+            None if interactive => interactive::prompt_for_root_path(),
+            None => unreachable!("Clap rules enforce path is present unless running interactively"),
+        };
 
         let maybe_workspace_path = arg_matches.get_one::<String>(WORKSPACE_PATH_ARG_NAME);
         let maybe_git_url = arg_matches.get_one::<String>(GIT_URL_ARG_NAME);
@@ -125,9 +345,69 @@ impl Args {
             _ => unreachable!("Clap rules enforce either both or neither git args are present"),
         };
 
+        let check = arg_matches.get_flag(CHECK_ARG_NAME);
+        let no_default_features = arg_matches.get_flag(NO_DEFAULT_FEATURES_ARG_NAME);
+        let no_tests = arg_matches.get_flag(NO_TESTS_ARG_NAME);
+        let tests_bin_name = arg_matches
+            .get_one::<String>(TESTS_BIN_NAME_ARG_NAME)
+            .expect("expected tests bin name")
+            .clone();
+        let no_compat_note = arg_matches.get_flag(NO_COMPAT_NOTE_ARG_NAME);
+        let required_tools = arg_matches
+            .get_many::<String>(REQUIRED_TOOLS_ARG_NAME)
+            .expect("expected required tools")
+            .cloned()
+            .collect();
+        let ci_provider = match arg_matches
+            .get_one::<String>(CI_ARG_NAME)
+            .expect("expected CI")
+            .as_str()
+        {
+            CI_ARG_GITHUB => Some(CiProvider::GitHub),
+            CI_ARG_TRAVIS => Some(CiProvider::Travis),
+            CI_ARG_NONE => None,
+            _ => unreachable!("Clap rules enforce CI is one of the possible values"),
+        };
+        let template_value = arg_matches
+            .get_one::<String>(TEMPLATE_ARG_NAME)
+            .expect("expected template");
+        let template_value = if interactive
+            && arg_matches.value_source(TEMPLATE_ARG_NAME) == Some(ValueSource::DefaultValue)
+        {
+            interactive::prompt_for_choice(TEMPLATE_PROMPT, &TEMPLATE_ARG_VALUES, template_value)
+        } else {
+            template_value.as_str()
+        };
+        let template = match template_value {
+            TEMPLATE_ARG_DEFAULT => Template::Default,
+            TEMPLATE_ARG_CEP18 => Template::Cep18,
+            TEMPLATE_ARG_CEP78 => Template::Cep78,
+            _ => unreachable!("Clap rules enforce template is one of the possible values"),
+        };
+        let toolchain = arg_matches
+            .get_one::<String>(TOOLCHAIN_ARG_NAME)
+            .cloned()
+            .unwrap_or_else(|| contract_package::RUST_TOOLCHAIN_CONTENTS.trim().to_string());
+        let package_name = arg_matches
+            .get_one::<String>(NAME_ARG_NAME)
+            .expect("expected name")
+            .clone();
+        let workspace = arg_matches.get_flag(WORKSPACE_ARG_NAME);
+
         Args {
             root_path,
             casper_overrides,
+            check,
+            no_default_features,
+            no_tests,
+            tests_bin_name,
+            no_compat_note,
+            required_tools,
+            ci_provider,
+            template,
+            toolchain,
+            package_name,
+            workspace,
         }
     }
 
@@ -138,10 +418,173 @@ impl Args {
     pub fn casper_overrides(&self) -> Option<&CasperOverrides> {
         self.casper_overrides.as_ref()
     }
+
+    pub fn check(&self) -> bool {
+        self.check
+    }
+
+    pub fn no_default_features(&self) -> bool {
+        self.no_default_features
+    }
+
+    pub fn no_tests(&self) -> bool {
+        self.no_tests
+    }
+
+    pub fn tests_bin_name(&self) -> &str {
+        &self.tests_bin_name
+    }
+
+    pub fn no_compat_note(&self) -> bool {
+        self.no_compat_note
+    }
+
+    pub fn required_tools(&self) -> &[String] {
+        &self.required_tools
+    }
+
+    pub fn ci_provider(&self) -> Option<CiProvider> {
+        self.ci_provider
+    }
+
+    pub fn template(&self) -> Template {
+        self.template
+    }
+
+    pub fn toolchain(&self) -> &str {
+        &self.toolchain
+    }
+
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
+
+    pub fn workspace(&self) -> bool {
+        self.workspace
+    }
+}
+
+/// Rust keywords, including those reserved for future use, which Cargo doesn't allow as target
+/// names.
+const RUST_KEYWORDS: [&str; 51] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Names which Cargo doesn't allow for binary targets as they clash with directories in its output
+/// directory.
+const FORBIDDEN_BIN_NAMES: [&str; 4] = ["build", "deps", "examples", "incremental"];
+
+/// The name of the directory Cargo builds into, which the contract package's directory would clash
+/// with when generated as a member of a workspace.
+const TARGET_DIR_NAME: &str = "target";
+
+/// Names of crates built into Rust, which Cargo doesn't allow as package names.
+const BUILT_IN_CRATE_NAMES: [&str; 5] = ["alloc", "core", "proc_macro", "std", "test"];
+
+/// Parses `value` as the name of a Cargo binary target, i.e. a non-empty string of ASCII
+/// alphanumerics, '-' and '_', starting with an ASCII letter or '_', which isn't a Rust keyword or
+/// a name reserved by Cargo.
+fn parse_target_name(value: &str) -> Result<String, String> {
+    if value.is_empty() {
+        return Err("name must not be empty".to_string());
+    }
+    if !value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        return Err(format!(
+            "'{}' must start with an ASCII letter or '_'",
+            value
+        ));
+    }
+    if let Some(invalid_char) = value
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '_')
+    {
+        return Err(format!(
+            "'{}' contains invalid character '{}': only ASCII alphanumerics, '-' and '_' are \
+            allowed",
+            value, invalid_char
+        ));
+    }
+    if RUST_KEYWORDS.contains(&value) {
+        return Err(format!("'{}' is a Rust keyword", value));
+    }
+    if FORBIDDEN_BIN_NAMES.contains(&value) {
+        return Err(format!("'{}' is reserved by Cargo", value));
+    }
+    Ok(value.to_string())
+}
+
+/// Parses `value` as the name of the contract package.  As well as being a valid target name, it
+/// mustn't clash with a built-in crate or a Casper dependency, and its directory mustn't clash with
+/// that of the tests package or Cargo's target directory.
+fn parse_package_name(value: &str) -> Result<String, String> {
+    let name = parse_target_name(value)?;
+    let normalized_name = name.replace('-', "_");
+    if BUILT_IN_CRATE_NAMES.contains(&normalized_name.as_str()) {
+        return Err(format!("'{}' clashes with a crate built into Rust", name));
+    }
+    let dependencies = [
+        &*common::CL_CONTRACT,
+        &*common::CL_TYPES,
+        &*common::CL_ENGINE_TEST_SUPPORT,
+        &*common::CL_EXECUTION_ENGINE,
+    ];
+    if let Some(dependency) = dependencies
+        .iter()
+        .find(|dependency| dependency.name().replace('-', "_") == normalized_name)
+    {
+        return Err(format!(
+            "'{}' clashes with the '{}' dependency",
+            name,
+            dependency.name()
+        ));
+    }
+    if normalized_name == tests_package::DIR_NAME {
+        return Err(format!(
+            "'{}' is reserved for the tests package",
+            tests_package::DIR_NAME
+        ));
+    }
+    if normalized_name == TARGET_DIR_NAME {
+        return Err(format!(
+            "'{}' is reserved for Cargo's target directory",
+            TARGET_DIR_NAME
+        ));
+    }
+    Ok(name)
+}
+
+/// Parses `value` as a rustup toolchain channel, i.e. a non-empty string of ASCII alphanumerics,
+/// '-', '_' and '.'.
+fn parse_toolchain_channel(value: &str) -> Result<String, String> {
+    if value.is_empty() {
+        return Err("channel must not be empty".to_string());
+    }
+    if let Some(invalid_char) = value
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '_' | '.'))
+    {
+        return Err(format!(
+            "'{}' contains invalid character '{}': only ASCII alphanumerics, '-', '_' and '.' are \
+            allowed",
+            value, invalid_char
+        ));
+    }
+    Ok(value.to_string())
 }
 
 fn main() {
-    if ARGS.root_path().exists() {
+    if ARGS.check() {
+        if !ARGS.root_path().is_dir() {
+            common::print_error_and_exit(&format!(
+                ": '{}' is not an existing directory",
+                ARGS.root_path().display()
+            ));
+        }
+    } else if ARGS.root_path().exists() {
         common::print_error_and_exit(&format!(
             ": destination '{}' already exists",
             ARGS.root_path().display()
@@ -150,7 +593,89 @@ fn main() {
 
     common::create_dir_all(ARGS.root_path());
     contract_package::create();
-    tests_package::create();
+    if !ARGS.no_tests() {
+        tests_package::create();
+    }
     makefile::create();
-    travis_yml::create();
+    if ARGS.workspace() {
+        workspace_manifest::create();
+    }
+    match ARGS.ci_provider() {
+        Some(CiProvider::GitHub) => github_workflow::create(),
+        Some(CiProvider::Travis) => travis_yml::create(),
+        None => (),
+    }
+    if !ARGS.no_compat_note() {
+        compatibility_md::create();
+    }
+
+    if ARGS.check() {
+        report_outdated_files();
+    }
+}
+
+/// Returns the paths of any files in the project which the current templates and options no longer
+/// generate, e.g. files written by an earlier version of the tool or with different options.
+fn stale_files() -> Vec<PathBuf> {
+    let mut candidates = vec![contract_package::LEGACY_RUST_TOOLCHAIN.clone()];
+    if ARGS.no_tests() {
+        candidates.push(tests_package::TESTS_PACKAGE_ROOT.clone());
+    }
+    if !ARGS.workspace() {
+        candidates.push(workspace_manifest::CARGO_TOML.clone());
+    }
+    if ARGS.no_compat_note() {
+        candidates.push(compatibility_md::COMPATIBILITY_MD.clone());
+    }
+    if ARGS.ci_provider() != Some(CiProvider::GitHub) {
+        candidates.push(github_workflow::WORKFLOW_YML.clone());
+    }
+    if ARGS.ci_provider() != Some(CiProvider::Travis) {
+        candidates.push(travis_yml::TRAVIS_YML.clone());
+    }
+    candidates
+        .into_iter()
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Prints the files found to differ from the current templates when run with `--check`, exiting
+/// with a failure code if there are any.
+fn report_outdated_files() {
+    for path in common::diverged_starter_files() {
+        let status = if path.exists() {
+            "differs from its starter template"
+        } else {
+            "is missing"
+        };
+        println!("'{}' {} (for information only)", path.display(), status);
+    }
+
+    let outdated_files = common::outdated_files();
+    let stale_files = stale_files();
+    if outdated_files.is_empty() && stale_files.is_empty() {
+        println!(
+            "'{}' is up to date with the current templates",
+            ARGS.root_path().display()
+        );
+        return;
+    }
+
+    for path in &outdated_files {
+        let status = if path.exists() {
+            "differs from the current template"
+        } else {
+            "is missing"
+        };
+        eprintln!("'{}' {}", path.display(), status);
+    }
+    for path in &stale_files {
+        eprintln!("'{}' is no longer generated", path.display());
+    }
+
+    common::print_error_and_exit(&format!(
+        ": {} file(s) in '{}' are out of date with the current templates",
+        outdated_files.len() + stale_files.len(),
+        ARGS.root_path().display()
+    ));
 }
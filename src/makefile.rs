@@ -1,8 +1,124 @@
-use crate::{common, ARGS};
+use once_cell::sync::Lazy;
+
+use crate::{
+    common,
+    contract_package::{BIN_NAME, WASM_FILENAME, WASM_TARGET},
+    ARGS,
+};
 
 const FILENAME: &str = "Makefile";
 const MAKEFILE_CONTENTS: &str = include_str!("../resources/Makefile.in");
+const NO_TESTS_MAKEFILE_CONTENTS: &str = include_str!("../resources/Makefile-no-tests.in");
+
+/// A tool which the generated Makefile's `check-tools` recipe can verify is installed.
+pub struct Tool {
+    pub name: &'static str,
+    install_hint: &'static str,
+    /// The apt package providing the tool, if it's not installed via rustup.
+    apt_package: Option<&'static str>,
+}
+
+/// All tools which can be specified via `--required-tools`.
+pub const TOOLS: [Tool; 4] = [
+    Tool {
+        name: "rustup",
+        install_hint: "install from https://rustup.rs",
+        apt_package: None,
+    },
+    Tool {
+        name: "cargo",
+        install_hint: "install via rustup from https://rustup.rs",
+        apt_package: None,
+    },
+    Tool {
+        name: "wasm-strip",
+        install_hint: "install wabt, e.g. 'sudo apt install wabt' or 'brew install wabt'",
+        apt_package: Some("wabt"),
+    },
+    Tool {
+        name: "wasm-opt",
+        install_hint:
+            "install binaryen, e.g. 'sudo apt install binaryen' or 'brew install binaryen'",
+        apt_package: Some("binaryen"),
+    },
+];
+
+/// The command in the templates' `build-contract` recipe which strips the built contract.
+const STRIP_COMMAND: &str =
+    "\twasm-strip $(CONTRACT_DIR)/target/$(WASM_TARGET)/release/$(CONTRACT_WASM)\n";
+/// Replaces `STRIP_COMMAND` if `wasm-strip` isn't a required tool, so that the build doesn't fail
+/// if it's not installed.
+const OPTIONAL_STRIP_COMMAND: &str =
+    "\twasm-strip $(CONTRACT_DIR)/target/$(WASM_TARGET)/release/$(CONTRACT_WASM) \
+    2>/dev/null || true\n";
+/// Added after `STRIP_COMMAND` if `wasm-opt` is a required tool, optimizing the built contract for
+/// size.
+const OPTIMIZE_COMMAND: &str =
+    "\twasm-opt -Oz -o $(CONTRACT_DIR)/target/$(WASM_TARGET)/release/$(CONTRACT_WASM) \
+    $(CONTRACT_DIR)/target/$(WASM_TARGET)/release/$(CONTRACT_WASM)\n";
+
+fn required_tools() -> impl Iterator<Item = &'static Tool> {
+    TOOLS
+        .iter()
+        .filter(|tool| ARGS.required_tools().iter().any(|name| name == tool.name))
+}
+
+static CHECK_TOOLS_RECIPE: Lazy<String> = Lazy::new(|| {
+    let checks: String = required_tools()
+        .map(|tool| {
+            format!(
+                "\t@command -v {0} >/dev/null 2>&1 || \
+                {{ echo \"'{0}' not found: {1}\"; exit 1; }}\n",
+                tool.name, tool.install_hint
+            )
+        })
+        .collect();
+    format!("check-tools:\n{}", checks)
+});
+
+fn is_required(tool_name: &str) -> bool {
+    required_tools().any(|tool| tool.name == tool_name)
+}
+
+static CONTENTS: Lazy<String> = Lazy::new(|| {
+    let recipes = if ARGS.no_tests() {
+        NO_TESTS_MAKEFILE_CONTENTS
+    } else {
+        MAKEFILE_CONTENTS
+    };
+    // Only the tools which `check-tools` verifies are installed are allowed to fail the build.
+    let mut post_build_commands = if is_required("wasm-strip") {
+        STRIP_COMMAND.to_string()
+    } else {
+        OPTIONAL_STRIP_COMMAND.to_string()
+    };
+    if is_required("wasm-opt") {
+        post_build_commands.push_str(OPTIMIZE_COMMAND);
+    }
+    let recipes = recipes.replace(STRIP_COMMAND, &post_build_commands);
+    format!(
+        "CONTRACT_DIR = {}\nCONTRACT_WASM = {}\nWASM_TARGET = {}\n\n{}\n{}",
+        &*BIN_NAME, &*WASM_FILENAME, WASM_TARGET, recipes, &*CHECK_TOOLS_RECIPE
+    )
+});
+
+/// Returns the apt packages which CI needs to install to provide the required tools.
+pub fn required_apt_packages() -> Vec<&'static str> {
+    required_tools()
+        .filter_map(|tool| tool.apt_package)
+        .collect()
+}
+
+/// Returns the Makefile target which CI should run after linting.  Without a tests package there's
+/// no `test` target, so just build the contract.
+pub fn ci_target() -> &'static str {
+    if ARGS.no_tests() {
+        "build-contract"
+    } else {
+        "test"
+    }
+}
 
 pub fn create() {
-    common::write_file(ARGS.root_path().join(FILENAME), MAKEFILE_CONTENTS);
+    common::write_file(ARGS.root_path().join(FILENAME), &*CONTENTS);
 }
@@ -9,15 +9,24 @@ use crate::{
     common::{
         self, CL_CONTRACT, CL_ENGINE_TEST_SUPPORT, CL_EXECUTION_ENGINE, CL_TYPES, PATCH_SECTION,
     },
-    ARGS,
+    contract_package::WASM_FILENAME,
+    Template, ARGS,
 };
 
-const PACKAGE_NAME: &str = "tests";
+/// The name of the tests package's directory.
+pub const DIR_NAME: &str = "tests";
+/// The name of the tests package, derived from that of the contract package so that the tests for
+/// several contracts can be members of the same workspace.
+static PACKAGE_NAME: Lazy<String> = Lazy::new(|| format!("{}-tests", ARGS.package_name()));
 
-static CONTRACT_PACKAGE_ROOT: Lazy<PathBuf> = Lazy::new(|| ARGS.root_path().join(PACKAGE_NAME));
-static CARGO_TOML: Lazy<PathBuf> = Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("Cargo.toml"));
-static INTEGRATION_TESTS_RS: Lazy<PathBuf> =
-    Lazy::new(|| CONTRACT_PACKAGE_ROOT.join("src/integration_tests.rs"));
+pub static TESTS_PACKAGE_ROOT: Lazy<PathBuf> = Lazy::new(|| ARGS.root_path().join(DIR_NAME));
+static CARGO_TOML: Lazy<PathBuf> = Lazy::new(|| TESTS_PACKAGE_ROOT.join("Cargo.toml"));
+static INTEGRATION_TESTS_RS: Lazy<PathBuf> = Lazy::new(|| {
+    TESTS_PACKAGE_ROOT.join(format!(
+        "src/{}.rs",
+        ARGS.tests_bin_name().replace('-', "_")
+    ))
+});
 
 pub static TEST_DEPENDENCIES: Lazy<String> = Lazy::new(|| {
     format!(
@@ -30,34 +39,58 @@ pub static TEST_DEPENDENCIES: Lazy<String> = Lazy::new(|| {
 });
 
 static CARGO_TOML_CONTENTS: Lazy<String> = Lazy::new(|| {
+    // When running with `--workspace`, the patch section is written to the workspace manifest.
+    let patch_section = if ARGS.workspace() {
+        String::new()
+    } else {
+        format!("\n{}", &*PATCH_SECTION)
+    };
     format!(
         r#"[package]
-name = "tests"
+name = "{}"
 version = "0.1.0"
 edition = "2021"
 
 [dev-dependencies]
 {}
 [[bin]]
-name = "integration-tests"
-path = "src/integration_tests.rs"
+name = "{}"
+path = "src/{}.rs"
 bench = false
 doctest = false
-
 {}"#,
-        &*TEST_DEPENDENCIES, &*PATCH_SECTION
+        &*PACKAGE_NAME,
+        &*TEST_DEPENDENCIES,
+        ARGS.tests_bin_name(),
+        ARGS.tests_bin_name().replace('-', "_"),
+        patch_section
     )
 });
 
+/// The name of the Wasm file used in the integration tests templates, replaced by the actual name.
+const TEMPLATE_WASM_FILENAME: &str = "contract.wasm";
+
 const INTEGRATION_TESTS_RS_CONTENTS: &str = include_str!("../resources/integration_tests.rs.in");
+const CEP18_INTEGRATION_TESTS_RS_CONTENTS: &str =
+    include_str!("../resources/cep18_integration_tests.rs.in");
+const CEP78_INTEGRATION_TESTS_RS_CONTENTS: &str =
+    include_str!("../resources/cep78_integration_tests.rs.in");
 
 pub fn create() {
     // Create "tests/src" folder and write test files inside.
     let tests_folder = INTEGRATION_TESTS_RS.parent().expect("should have parent");
     common::create_dir_all(tests_folder);
 
-    // Write "tests/integration_tests.rs".
-    common::write_file(&*INTEGRATION_TESTS_RS, INTEGRATION_TESTS_RS_CONTENTS);
+    // Write "tests/src/<tests bin name>.rs".
+    let integration_tests_rs_contents = match ARGS.template() {
+        Template::Default => INTEGRATION_TESTS_RS_CONTENTS,
+        Template::Cep18 => CEP18_INTEGRATION_TESTS_RS_CONTENTS,
+        Template::Cep78 => CEP78_INTEGRATION_TESTS_RS_CONTENTS,
+    };
+    common::write_starter_file(
+        &*INTEGRATION_TESTS_RS,
+        integration_tests_rs_contents.replace(TEMPLATE_WASM_FILENAME, &WASM_FILENAME),
+    );
 
     // Write "tests/Cargo.toml".
     common::write_file(&*CARGO_TOML, &*CARGO_TOML_CONTENTS);
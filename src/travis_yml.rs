@@ -1,13 +1,33 @@
-use crate::{common, ARGS};
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+
+use crate::{common, makefile, ARGS};
 
 const FILENAME: &str = ".travis.yml";
-const CONTENTS: &str = r#"language: rust
-script:
+pub static TRAVIS_YML: Lazy<PathBuf> = Lazy::new(|| ARGS.root_path().join(FILENAME));
+static CONTENTS: Lazy<String> = Lazy::new(|| {
+    let apt_packages: String = makefile::required_apt_packages()
+        .into_iter()
+        .map(|package| format!("      - {}\n", package))
+        .collect();
+    let addons = if apt_packages.is_empty() {
+        String::new()
+    } else {
+        format!("addons:\n  apt:\n    packages:\n{}", apt_packages)
+    };
+    format!(
+        r#"language: rust
+{}script:
   - make prepare
   - make check-lint
-  - make test
-"#;
+  - make {}
+"#,
+        addons,
+        makefile::ci_target()
+    )
+});
 
 pub fn create() {
-    common::write_file(ARGS.root_path().join(FILENAME), CONTENTS);
+    common::write_file(&*TRAVIS_YML, &*CONTENTS);
 }
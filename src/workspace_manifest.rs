@@ -0,0 +1,37 @@
+//! Consts and functions used to generate the top-level workspace "Cargo.toml" when running the tool
+//! with `--workspace`.
+
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    common::{self, PATCH_SECTION},
+    contract_package, tests_package, ARGS,
+};
+
+pub static CARGO_TOML: Lazy<PathBuf> = Lazy::new(|| ARGS.root_path().join("Cargo.toml"));
+
+// Cargo ignores patch sections in member manifests, so the tests package's is written here rather
+// than in its own manifest.
+//
+// The contract is excluded rather than made a member, so it keeps its own "Cargo.lock".  It's built
+// with its pinned toolchain, whose Cargo may be unable to read a lockfile written by a newer Cargo
+// run at the workspace root.
+static CONTENTS: Lazy<String> = Lazy::new(|| {
+    format!(
+        r#"[workspace]
+members = ["{}"]
+exclude = ["{}"]
+resolver = "2"
+
+{}"#,
+        tests_package::DIR_NAME,
+        &*contract_package::BIN_NAME,
+        &*PATCH_SECTION
+    )
+});
+
+pub fn create() {
+    common::write_file(&*CARGO_TOML, &*CONTENTS);
+}
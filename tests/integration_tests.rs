@@ -1,4 +1,8 @@
-use std::{env, fs, process::Output};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Output,
+};
 
 use assert_cmd::Command;
 
@@ -6,6 +10,8 @@ const FAILURE_EXIT_CODE: i32 = 101;
 const SUCCESS_EXIT_CODE: i32 = 0;
 const TEST_PATH: &str = "test";
 const GIT_URL_ARG: &str = "--git-url=https://github.com/casper-network/casper-node";
+/// The path of the built contract in the generated Makefile.
+const BUILT_WASM: &str = "$(CONTRACT_DIR)/target/$(WASM_TARGET)/release/$(CONTRACT_WASM)";
 /// GitHub Actions doesn't have good support for running scheduled jobs on non-default branches.
 /// To work around this, our CI configuration will set an env var `BRANCH_SELECTOR` to the
 /// appropriate branch name.  It will be unset on non-scheduled runs (e.g. merges, PRs).
@@ -48,14 +54,635 @@ fn output_from_command(mut command: Command) -> Output {
     }
 }
 
-fn run_make_test_on_generated_project(maybe_git_branch_arg: Option<String>) {
+/// Runs `cargo-casper <test_dir> <args>`, panicking on failure.
+fn generate_project(test_dir: &Path, args: &[&str]) {
+    let mut tool_cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    tool_cmd.arg(test_dir).args(args);
+    let tool_output = output_from_command(tool_cmd);
+    assert_eq!(SUCCESS_EXIT_CODE, tool_output.status.code().unwrap());
+}
+
+#[test]
+fn should_report_modified_makefile_when_checking() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    // An unmodified project should pass the check.
+    generate_project(&test_dir, &["--check"]);
+
+    let makefile = test_dir.join("Makefile");
+    let mut contents = fs::read_to_string(&makefile).unwrap();
+    contents.push_str("\nextra-target:\n\techo extra\n");
+    fs::write(&makefile, contents).unwrap();
+
+    let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&test_dir)
+        .arg("--check")
+        .unwrap_err();
+
+    let exit_code = output_error.as_output().unwrap().status.code().unwrap();
+    assert_eq!(FAILURE_EXIT_CODE, exit_code);
+
+    let stderr: String = String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+    let expected_msg_fragment =
+        format!("'{}' differs from the current template", makefile.display());
+    assert!(stderr.contains(&expected_msg_fragment));
+    assert!(stderr.contains("1 file(s)"));
+
+    // The check shouldn't have modified the file.
+    assert!(fs::read_to_string(&makefile)
+        .unwrap()
+        .ends_with("extra-target:\n\techo extra\n"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_only_inform_about_modified_starter_files_when_checking() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let main_rs = test_dir.join("contract/src/main.rs");
+    let mut contents = fs::read_to_string(&main_rs).unwrap();
+    contents.push_str("\n// An edit made by the user.\n");
+    fs::write(&main_rs, contents).unwrap();
+
+    let mut tool_cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    tool_cmd.arg(&test_dir).arg("--check");
+    let tool_output = output_from_command(tool_cmd);
+    assert_eq!(SUCCESS_EXIT_CODE, tool_output.status.code().unwrap());
+
+    let stdout = String::from_utf8_lossy(&tool_output.stdout);
+    let expected_msg_fragment = format!(
+        "'{}' differs from its starter template (for information only)",
+        main_rs.display()
+    );
+    assert!(stdout.contains(&expected_msg_fragment));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_report_stale_files_when_checking() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let legacy_toolchain = test_dir.join("contract/rust-toolchain");
+    fs::write(&legacy_toolchain, "nightly\n").unwrap();
+    let travis_yml = test_dir.join(".travis.yml");
+    fs::write(&travis_yml, "language: rust\n").unwrap();
+
+    let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&test_dir)
+        .arg("--check")
+        .unwrap_err();
+
+    let exit_code = output_error.as_output().unwrap().status.code().unwrap();
+    assert_eq!(FAILURE_EXIT_CODE, exit_code);
+
+    let stderr: String = String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+    for path in [&legacy_toolchain, &travis_yml] {
+        assert!(stderr.contains(&format!("'{}' is no longer generated", path.display())));
+    }
+    assert!(stderr.contains("2 file(s)"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_report_files_not_generated_with_current_options_when_checking() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--workspace"]);
+
+    let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&test_dir)
+        .args(["--check", "--no-tests", "--no-compat-note"])
+        .unwrap_err();
+
+    let exit_code = output_error.as_output().unwrap().status.code().unwrap();
+    assert_eq!(FAILURE_EXIT_CODE, exit_code);
+
+    let stderr: String = String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+    for path in ["Cargo.toml", "tests", "COMPATIBILITY.md"] {
+        let expected_msg_fragment =
+            format!("'{}' is no longer generated", test_dir.join(path).display());
+        assert!(stderr.contains(&expected_msg_fragment));
+    }
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+/// Returns the line of the manifest at `manifest_path` specifying the dependency `name`.
+fn dependency_line(manifest_path: &Path, name: &str) -> String {
+    fs::read_to_string(manifest_path)
+        .unwrap()
+        .lines()
+        .find(|line| line.starts_with(&format!("{} = ", name)))
+        .unwrap_or_else(|| panic!("{} should list {}", manifest_path.display(), name))
+        .to_string()
+}
+
+#[test]
+fn should_disable_default_features_in_contract_manifest() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--no-default-features"]);
+
+    let manifest_path = test_dir.join("contract/Cargo.toml");
+    let contract_dependency = dependency_line(&manifest_path, "casper-contract");
+    assert!(contract_dependency
+        .ends_with(r#", default-features = false, features = ["no-std-helpers"] }"#));
+    let types_dependency = dependency_line(&manifest_path, "casper-types");
+    assert!(types_dependency.ends_with(", default-features = false }"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_use_default_features_in_contract_manifest_by_default() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let manifest_path = test_dir.join("contract/Cargo.toml");
+    assert!(!dependency_line(&manifest_path, "casper-contract").contains("default-features"));
+    assert!(!dependency_line(&manifest_path, "casper-types").contains("default-features"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_omit_tests_package_when_requested() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--no-tests"]);
+
+    assert!(test_dir.join("contract/Cargo.toml").is_file());
+    assert!(!test_dir.join("tests").exists());
+
+    let makefile = fs::read_to_string(test_dir.join("Makefile")).unwrap();
+    assert!(makefile.contains("\nbuild-contract:\n"));
+    assert!(!makefile.contains("\ntest:"));
+    assert!(!makefile.contains("cd tests"));
+
+    let workflow = fs::read_to_string(test_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(workflow.contains("make build-contract"));
+    assert!(!workflow.contains("make test"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_use_custom_tests_bin_name() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--tests-bin-name=contract-tests"]);
+
+    let manifest = fs::read_to_string(test_dir.join("tests/Cargo.toml")).unwrap();
+    assert!(
+        manifest.contains("[[bin]]\nname = \"contract-tests\"\npath = \"src/contract_tests.rs\"\n")
+    );
+    assert!(test_dir.join("tests/src/contract_tests.rs").is_file());
+    assert!(!test_dir.join("tests/src/integration_tests.rs").exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_fail_with_invalid_tests_bin_name() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    for (name, expected_msg_fragment) in [
+        ("bad.name", "contains invalid character '.'"),
+        ("-tests", "must start with an ASCII letter or '_'"),
+        ("fn", "'fn' is a Rust keyword"),
+        ("build", "'build' is reserved by Cargo"),
+    ] {
+        let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&test_dir)
+            .arg(format!("--tests-bin-name={}", name))
+            .unwrap_err();
+
+        let stderr: String =
+            String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+        assert!(stderr.contains(expected_msg_fragment));
+        assert!(!test_dir.exists());
+    }
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_use_custom_package_name() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--name=my-token"]);
+
+    let manifest = fs::read_to_string(test_dir.join("my_token/Cargo.toml")).unwrap();
+    assert!(manifest.starts_with("[package]\nname = \"my-token\"\n"));
+    assert!(manifest.contains("[[bin]]\nname = \"my_token\"\npath = \"src/main.rs\"\n"));
+    assert!(!test_dir.join("contract").exists());
+    let tests_manifest = fs::read_to_string(test_dir.join("tests/Cargo.toml")).unwrap();
+    assert!(tests_manifest.starts_with("[package]\nname = \"my-token-tests\"\n"));
+
+    let makefile = fs::read_to_string(test_dir.join("Makefile")).unwrap();
+    assert!(makefile.starts_with("CONTRACT_DIR = my_token\nCONTRACT_WASM = my_token.wasm\n"));
+    let integration_tests_rs =
+        fs::read_to_string(test_dir.join("tests/src/integration_tests.rs")).unwrap();
+    assert!(integration_tests_rs.contains("const CONTRACT_WASM: &str = \"my_token.wasm\";"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_fail_with_invalid_package_name() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    for (name, expected_msg_fragment) in [
+        ("1token", "must start with an ASCII letter or '_'"),
+        ("-token", "must start with an ASCII letter or '_'"),
+        ("fn", "'fn' is a Rust keyword"),
+        ("self", "'self' is a Rust keyword"),
+        ("build", "'build' is reserved by Cargo"),
+        ("deps", "'deps' is reserved by Cargo"),
+        ("test", "'test' clashes with a crate built into Rust"),
+        ("casper-types", "clashes with the 'casper-types' dependency"),
+        ("casper_contract", "with the 'casper-contract' dependency"),
+        ("tests", "'tests' is reserved for the tests package"),
+        (
+            "target",
+            "'target' is reserved for Cargo's target directory",
+        ),
+    ] {
+        let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&test_dir)
+            .arg(format!("--name={}", name))
+            .unwrap_err();
+
+        let stderr: String =
+            String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+        assert!(stderr.contains(expected_msg_fragment));
+        assert!(!test_dir.exists());
+    }
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_workspace_manifest_when_requested() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--workspace", GIT_URL_ARG, "--git-branch=dev"]);
+
+    let workspace_manifest = fs::read_to_string(test_dir.join("Cargo.toml")).unwrap();
+    assert!(workspace_manifest
+        .starts_with("[workspace]\nmembers = [\"tests\"]\nexclude = [\"contract\"]\n"));
+    assert!(workspace_manifest.contains("\n[patch.crates-io]\n"));
+
+    // Cargo ignores patches in member manifests, so the tests package's should only be in the root.
+    let tests_manifest = fs::read_to_string(test_dir.join("tests/Cargo.toml")).unwrap();
+    assert!(!tests_manifest.contains("[patch.crates-io]"));
+
+    // The contract isn't a member, so its manifest should retain its profile and patch.
+    let contract_manifest = fs::read_to_string(test_dir.join("contract/Cargo.toml")).unwrap();
+    assert!(contract_manifest.contains("\n[profile.release]\n"));
+    assert!(contract_manifest.contains("\n[patch.crates-io]\n"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_exclude_custom_package_name_from_workspace() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--workspace", "--name=my-token"]);
+
+    let workspace_manifest = fs::read_to_string(test_dir.join("Cargo.toml")).unwrap();
+    assert!(workspace_manifest.contains("\nexclude = [\"my_token\"]\n"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+/// Returns the path of the root manifest of the workspace containing `package_dir`, as found by
+/// `cargo locate-project`.
+fn workspace_root_manifest(package_dir: &Path) -> PathBuf {
+    // Run the Cargo running this test directly, so the contract's pinned toolchain isn't installed.
+    let mut cargo_cmd = Command::new(env::var("CARGO").unwrap());
+    cargo_cmd
+        .args(["locate-project", "--workspace", "--message-format=plain"])
+        .current_dir(package_dir);
+    let cargo_output = output_from_command(cargo_cmd);
+    PathBuf::from(String::from_utf8_lossy(&cargo_output.stdout).trim())
+}
+
+#[test]
+fn should_keep_contract_lockfile_separate_from_workspace() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--workspace"]);
+
+    // The contract is built with its pinned toolchain, whose Cargo may be unable to read a lockfile
+    // written by running a newer Cargo at the root, so it should be its own workspace root.
+    let test_dir = test_dir.canonicalize().unwrap();
+    assert_eq!(
+        workspace_root_manifest(&test_dir.join("tests")),
+        test_dir.join("Cargo.toml")
+    );
+    assert_eq!(
+        workspace_root_manifest(&test_dir.join("contract")),
+        test_dir.join("contract/Cargo.toml")
+    );
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_not_generate_workspace_manifest_by_default() {
     let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
 
-    // Run 'cargo-casper <test dir>/<subdir>'
+    assert!(!test_dir.join("Cargo.toml").exists());
+    let manifest = fs::read_to_string(test_dir.join("contract/Cargo.toml")).unwrap();
+    assert!(manifest.contains("\n[profile.release]\n"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_record_casper_crate_versions_in_compatibility_note() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let note = fs::read_to_string(test_dir.join("COMPATIBILITY.md")).unwrap();
+    let manifest_path = test_dir.join("contract/Cargo.toml");
+    for name in ["casper-contract", "casper-types"] {
+        // The contract manifest lines are of the form `<name> = "<version>"`.
+        let version = dependency_line(&manifest_path, name)
+            .split('"')
+            .nth(1)
+            .unwrap()
+            .to_string();
+        assert!(note.contains(&format!("* `{}` {}\n", name, version)));
+    }
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_omit_compatibility_note_when_requested() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--no-compat-note"]);
+
+    assert!(!test_dir.join("COMPATIBILITY.md").exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_check_tools_recipe_in_makefile() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let makefile = fs::read_to_string(test_dir.join("Makefile")).unwrap();
+    assert!(makefile.contains("\n\ncheck-tools:\n"));
+    assert!(makefile.contains("\nprepare: check-tools\n"));
+    assert!(makefile.contains("command -v rustup "));
+    assert!(makefile.contains("command -v cargo "));
+    assert!(makefile.contains("command -v wasm-strip "));
+    assert!(!makefile.contains("command -v wasm-opt "));
+
+    // `wasm-strip` is required, so failing to run it should fail the build.
+    assert!(makefile.contains(&format!("\n\twasm-strip {}\n", BUILT_WASM)));
+    assert!(!makefile.contains("wasm-opt "));
+
+    // `prepare` should remain the first rule, so is run by a bare `make`.
+    let first_rule = makefile
+        .lines()
+        .find(|line| line.contains(':') && !line.starts_with('\t'))
+        .unwrap();
+    assert_eq!(first_rule, "prepare: check-tools");
+
+    let workflow = fs::read_to_string(test_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(workflow.contains("sudo apt-get install -y wabt\n"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_check_for_specified_tools_in_makefile() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--required-tools=rustup,wasm-strip"]);
+
+    let makefile = fs::read_to_string(test_dir.join("Makefile")).unwrap();
+    assert!(makefile.contains("command -v rustup "));
+    assert!(makefile.contains("command -v wasm-strip "));
+    assert!(!makefile.contains("command -v cargo "));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_only_run_required_tools_strictly_when_building_contract() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--required-tools=rustup,cargo,wasm-opt"]);
+
+    let makefile = fs::read_to_string(test_dir.join("Makefile")).unwrap();
+    assert!(makefile.contains(&format!(
+        "\n\twasm-strip {} 2>/dev/null || true\n",
+        BUILT_WASM
+    )));
+    assert!(makefile.contains(&format!("\n\twasm-opt -Oz -o {0} {0}\n\n", BUILT_WASM)));
+
+    let workflow = fs::read_to_string(test_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(workflow.contains("sudo apt-get install -y binaryen\n"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_github_workflow_by_default() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let workflow = fs::read_to_string(test_dir.join(".github/workflows/ci.yml")).unwrap();
+    for target in ["prepare", "check-lint", "test"] {
+        assert!(workflow.contains(&format!("- run: make {}\n", target)));
+    }
+    let toolchain = include_str!("../resources/rust-toolchain.in").trim();
+    assert!(workflow.contains(&format!("rustup toolchain install {} ", toolchain)));
+    assert!(!test_dir.join(".travis.yml").exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_rust_toolchain_toml() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &[]);
+
+    let toolchain_toml = fs::read_to_string(test_dir.join("contract/rust-toolchain.toml")).unwrap();
+    let toolchain = include_str!("../resources/rust-toolchain.in").trim();
+    assert!(toolchain_toml.starts_with("[toolchain]\n"));
+    assert!(toolchain_toml.contains(&format!("channel = \"{}\"\n", toolchain)));
+    assert!(toolchain_toml.contains("targets = [\"wasm32-unknown-unknown\"]\n"));
+    assert!(!test_dir.join("contract/rust-toolchain").exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_pin_specified_toolchain() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--toolchain=stable"]);
+
+    let toolchain_toml = fs::read_to_string(test_dir.join("contract/rust-toolchain.toml")).unwrap();
+    assert!(toolchain_toml.contains("channel = \"stable\"\n"));
+    let workflow = fs::read_to_string(test_dir.join(".github/workflows/ci.yml")).unwrap();
+    assert!(workflow.contains("rustup toolchain install stable "));
+    let compatibility_md = fs::read_to_string(test_dir.join("COMPATIBILITY.md")).unwrap();
+    assert!(compatibility_md.contains("Rust toolchain `stable`"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_travis_config_when_requested() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--ci=travis"]);
+
+    let travis_yml = fs::read_to_string(test_dir.join(".travis.yml")).unwrap();
+    assert!(travis_yml.contains("make test"));
+    assert!(!test_dir.join(".github").exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_no_ci_config_when_requested() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--ci=none"]);
+
+    assert!(!test_dir.join(".travis.yml").exists());
+    assert!(!test_dir.join(".github").exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_cep18_template() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--template=cep18"]);
+
+    let main_rs = fs::read_to_string(test_dir.join("contract/src/main.rs")).unwrap();
+    assert_eq!(main_rs, include_str!("../resources/cep18_main.rs.in"));
+    let integration_tests_rs =
+        fs::read_to_string(test_dir.join("tests/src/integration_tests.rs")).unwrap();
+    assert_eq!(
+        integration_tests_rs,
+        include_str!("../resources/cep18_integration_tests.rs.in")
+    );
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_generate_cep78_template() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    generate_project(&test_dir, &["--template=cep78"]);
+
+    let main_rs = fs::read_to_string(test_dir.join("contract/src/main.rs")).unwrap();
+    assert_eq!(main_rs, include_str!("../resources/cep78_main.rs.in"));
+    let integration_tests_rs =
+        fs::read_to_string(test_dir.join("tests/src/integration_tests.rs")).unwrap();
+    assert_eq!(
+        integration_tests_rs,
+        include_str!("../resources/cep78_integration_tests.rs.in")
+    );
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_fail_with_unknown_template() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&test_dir)
+        .arg("--template=erc20")
+        .unwrap_err();
+
+    let stderr: String = String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+    assert!(stderr.contains("invalid value 'erc20'"));
+    assert!(!test_dir.exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_prompt_for_path_and_template_when_interactive() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let test_dir = temp_dir.join(TEST_PATH);
+    let mut tool_cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    tool_cmd
+        .arg("--interactive")
+        .current_dir(&temp_dir)
+        .write_stdin(format!("{}\ncep18\n", TEST_PATH));
+    let tool_output = output_from_command(tool_cmd);
+    assert_eq!(SUCCESS_EXIT_CODE, tool_output.status.code().unwrap());
+
+    let main_rs = fs::read_to_string(test_dir.join("contract/src/main.rs")).unwrap();
+    assert_eq!(main_rs, include_str!("../resources/cep18_main.rs.in"));
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+#[test]
+fn should_fail_without_path_when_not_interactive() {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+    let output_error = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .current_dir(&temp_dir)
+        .write_stdin(format!("{}\n", TEST_PATH))
+        .unwrap_err();
+
+    let stderr: String = String::from_utf8_lossy(&output_error.as_output().unwrap().stderr).into();
+    assert!(stderr.contains("required arguments were not provided"));
+    assert!(!temp_dir.join(TEST_PATH).exists());
+
+    fs::remove_dir_all(&temp_dir).unwrap();
+}
+
+fn run_make_test_on_generated_project(maybe_git_branch_arg: Option<String>, args: &[&str]) {
+    let temp_dir = tempfile::tempdir().unwrap().into_path();
+
+    // Run 'cargo-casper <test dir>/<subdir> <args>'
     let subdir = TEST_PATH;
     let test_dir = temp_dir.join(subdir);
     let mut tool_cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
-    tool_cmd.arg(&test_dir);
+    tool_cmd.arg(&test_dir).args(args);
     if let Some(git_branch_arg) = maybe_git_branch_arg {
         // Append '--git-url=...' and '--git-branch=...' args.
         tool_cmd.arg(GIT_URL_ARG);
@@ -118,27 +745,84 @@ fn ci_branch_name() -> Option<String> {
 /// is run.  If not, the test is an auto-pass.
 #[test]
 fn should_run_cargo_casper_using_published_crates() {
+    if should_run_using_published_crates("should_run_cargo_casper_using_published_crates") {
+        run_make_test_on_generated_project(None, &[])
+    }
+}
+
+/// Returns whether the test named `test_name`, which builds a generated project using the
+/// published Casper crates, should run, i.e. whether the CI branch name starts with "release-".
+/// If not, prints why the test is being skipped.
+fn should_run_using_published_crates(test_name: &str) -> bool {
     match ci_branch_name() {
-        Some(branch_name) if branch_name.starts_with("release-") => (),
+        Some(branch_name) if branch_name.starts_with("release-") => true,
         Some(branch_name) => {
             println!(
-                "skipping 'should_run_cargo_casper_using_published_crates' as branch name '{}' \
-                doesn't start with 'release-'",
-                branch_name
+                "skipping '{}' as branch name '{}' doesn't start with 'release-'",
+                test_name, branch_name
             );
-            return;
+            false
         }
         None => {
             println!(
-                "skipping 'should_run_cargo_casper_using_published_crates' as {} and {} are unset \
-                or set to empty strings",
-                PR_TARGET_BRANCH_NAME_ENV_VAR, CI_BRANCH_NAME_ENV_VAR
+                "skipping '{}' as {} and {} are unset or set to empty strings",
+                test_name, PR_TARGET_BRANCH_NAME_ENV_VAR, CI_BRANCH_NAME_ENV_VAR
             );
-            return;
+            false
         }
     }
+}
+
+/// Checks that running `cargo-casper --no-default-features` yields a generated project which
+/// passes `make test`, i.e. that the contract builds with only the features it explicitly enables.
+///
+/// Run under the same conditions as `should_run_cargo_casper_using_published_crates`.
+#[test]
+fn should_run_cargo_casper_without_default_features_using_published_crates() {
+    if should_run_using_published_crates(
+        "should_run_cargo_casper_without_default_features_using_published_crates",
+    ) {
+        run_make_test_on_generated_project(None, &["--no-default-features"])
+    }
+}
+
+/// Checks that running `cargo-casper --template=cep18` yields a generated project which passes
+/// `make test`, i.e. that the CEP-18 fungible token contract builds and its tests pass.
+///
+/// Run under the same conditions as `should_run_cargo_casper_using_published_crates`.
+#[test]
+fn should_run_cargo_casper_with_cep18_template_using_published_crates() {
+    if should_run_using_published_crates(
+        "should_run_cargo_casper_with_cep18_template_using_published_crates",
+    ) {
+        run_make_test_on_generated_project(None, &["--template=cep18"])
+    }
+}
 
-    run_make_test_on_generated_project(None)
+/// Checks that running `cargo-casper --template=cep78` yields a generated project which passes
+/// `make test`, i.e. that the CEP-78 non-fungible token contract builds and its tests pass.
+///
+/// Run under the same conditions as `should_run_cargo_casper_using_published_crates`.
+#[test]
+fn should_run_cargo_casper_with_cep78_template_using_published_crates() {
+    if should_run_using_published_crates(
+        "should_run_cargo_casper_with_cep78_template_using_published_crates",
+    ) {
+        run_make_test_on_generated_project(None, &["--template=cep78"])
+    }
+}
+
+/// Checks that running `cargo-casper --workspace` yields a generated project which passes
+/// `make test`.
+///
+/// Run under the same conditions as `should_run_cargo_casper_using_published_crates`.
+#[test]
+fn should_run_cargo_casper_with_workspace_using_published_crates() {
+    if should_run_using_published_crates(
+        "should_run_cargo_casper_with_workspace_using_published_crates",
+    ) {
+        run_make_test_on_generated_project(None, &["--workspace"])
+    }
 }
 
 /// Checks that running `cargo-casper` with Git overrides yields a generated project which passes
@@ -187,5 +871,5 @@ fn should_run_cargo_casper_using_git_overrides() {
         }
     };
 
-    run_make_test_on_generated_project(Some(git_branch_arg))
+    run_make_test_on_generated_project(Some(git_branch_arg), &[])
 }